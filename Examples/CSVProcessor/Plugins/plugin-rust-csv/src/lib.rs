@@ -26,6 +26,93 @@ struct PluginInfo {
     actions: Vec<ActionDef>,
 }
 
+/// The fixed set of failure modes an action can report, serialized as the
+/// snake_case strings ARO callers match on (`"missing_field"`,
+/// `"malformed_payload"`, `"invalid_utf8"`, `"unknown_action"`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ErrorKind {
+    MissingField,
+    MalformedPayload,
+    InvalidUtf8,
+    UnknownAction,
+}
+
+/// Structured error returned by a failed action.
+///
+/// Carries enough detail for ARO callers to branch on failure mode
+/// (`kind`), know which representation it happened in (`format`), and, for
+/// CSV parse errors that expose a position, where in the input it occurred.
+#[derive(Debug, Serialize)]
+struct PluginError {
+    kind: ErrorKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    format: Option<&'static str>,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    byte: Option<u64>,
+}
+
+impl PluginError {
+    fn missing_field(format: &'static str, field: &str) -> Self {
+        PluginError {
+            kind: ErrorKind::MissingField,
+            format: Some(format),
+            message: format!("Missing '{}' field", field),
+            line: None,
+            byte: None,
+        }
+    }
+
+    fn malformed_payload(format: &'static str, message: impl Into<String>) -> Self {
+        PluginError {
+            kind: ErrorKind::MalformedPayload,
+            format: Some(format),
+            message: message.into(),
+            line: None,
+            byte: None,
+        }
+    }
+
+    fn invalid_utf8(message: impl Into<String>) -> Self {
+        PluginError {
+            kind: ErrorKind::InvalidUtf8,
+            format: None,
+            message: message.into(),
+            line: None,
+            byte: None,
+        }
+    }
+
+    fn unknown_action(action: &str) -> Self {
+        PluginError {
+            kind: ErrorKind::UnknownAction,
+            format: None,
+            message: format!("Unknown action: {}", action),
+            line: None,
+            byte: None,
+        }
+    }
+
+    /// Build a `malformed_payload` error from a `csv::Error`, attaching the
+    /// 1-based line and byte offset when the error exposes a position.
+    fn from_csv_error(format: &'static str, err: &csv::Error) -> Self {
+        let (line, byte) = match err.position() {
+            Some(pos) => (Some(pos.line()), Some(pos.byte())),
+            None => (None, None),
+        };
+        PluginError {
+            kind: ErrorKind::MalformedPayload,
+            format: Some(format),
+            message: err.to_string(),
+            line,
+            byte,
+        }
+    }
+}
+
 /// Get plugin information
 ///
 /// Returns JSON string with plugin metadata and custom action definitions.
@@ -54,6 +141,24 @@ pub extern "C" fn aro_plugin_info() -> *mut c_char {
                 verbs: vec!["csvtojson"],
                 prepositions: vec!["from"],
             },
+            ActionDef {
+                name: "JSONToCSV",
+                role: "own",
+                verbs: vec!["jsontocsv"],
+                prepositions: vec!["from"],
+            },
+            ActionDef {
+                name: "NDJSONToCSV",
+                role: "own",
+                verbs: vec!["ndjsontocsv"],
+                prepositions: vec!["from"],
+            },
+            ActionDef {
+                name: "GenerateCSV",
+                role: "own",
+                verbs: vec!["generatecsv"],
+                prepositions: vec!["with"],
+            },
         ],
     };
 
@@ -79,28 +184,33 @@ pub extern "C" fn aro_plugin_execute(
     // Safety: We trust the caller to provide valid C strings
     let action = unsafe {
         if action.is_null() {
-            return error_result("Action is null");
+            return error_result(&PluginError::invalid_utf8("Action is null"));
         }
         match CStr::from_ptr(action).to_str() {
             Ok(s) => s,
-            Err(_) => return error_result("Invalid action string"),
+            Err(_) => return error_result(&PluginError::invalid_utf8("Invalid action string")),
         }
     };
 
     let input = unsafe {
         if input_json.is_null() {
-            return error_result("Input is null");
+            return error_result(&PluginError::invalid_utf8("Input is null"));
         }
         match CStr::from_ptr(input_json).to_str() {
             Ok(s) => s,
-            Err(_) => return error_result("Invalid input string"),
+            Err(_) => return error_result(&PluginError::invalid_utf8("Invalid input string")),
         }
     };
 
     // Parse input JSON
     let input_value: Value = match serde_json::from_str(input) {
         Ok(v) => v,
-        Err(e) => return error_result(&format!("Invalid JSON input: {}", e)),
+        Err(e) => {
+            return error_result(&PluginError::malformed_payload(
+                "json",
+                format!("Invalid JSON input: {}", e),
+            ))
+        }
     };
 
     // Dispatch to the appropriate action
@@ -109,7 +219,10 @@ pub extern "C" fn aro_plugin_execute(
         "parse-csv" | "parsecsv" | "readcsv" => parse_csv(&input_value),
         "format-csv" | "formatcsv" | "writecsv" => format_csv(&input_value),
         "csv-to-json" | "csvtojson" => csv_to_json(&input_value),
-        _ => Err(format!("Unknown action: {}", action)),
+        "json-to-csv" | "jsontocsv" => json_to_csv(&input_value),
+        "ndjson-to-csv" | "ndjsontocsv" => ndjson_to_csv(&input_value),
+        "generate-csv" | "generatecsv" => generate_csv(&input_value),
+        _ => Err(PluginError::unknown_action(action)),
     };
 
     // Convert result to JSON string
@@ -130,28 +243,103 @@ pub extern "C" fn aro_plugin_free(ptr: *mut c_char) {
 }
 
 // Helper to create error result
-fn error_result(message: &str) -> *mut c_char {
-    let error = json!({ "error": message });
-    CString::new(error.to_string()).unwrap().into_raw()
+fn error_result(error: &PluginError) -> *mut c_char {
+    let json = serde_json::to_string(error).unwrap_or_else(|_| {
+        json!({ "kind": "malformed_payload", "message": "Failed to serialize error" }).to_string()
+    });
+    CString::new(json).unwrap().into_raw()
 }
 
 // MARK: - Actions
 
+/// Pull a single byte out of a one-character JSON string field.
+fn char_field(input: &Value, key: &str) -> Result<Option<u8>, PluginError> {
+    match input.get(key).and_then(|v| v.as_str()) {
+        Some(s) => {
+            let ch = s.chars().next().ok_or_else(|| {
+                PluginError::malformed_payload(
+                    "csv",
+                    format!("'{}' must be a single character", key),
+                )
+            })?;
+            if !ch.is_ascii() {
+                return Err(PluginError::malformed_payload(
+                    "csv",
+                    format!("'{}' must be a single ASCII character", key),
+                ));
+            }
+            Ok(Some(ch as u8))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Build a `csv::ReaderBuilder` from an optional `dialect` object in the
+/// input, covering delimiter, quote, escape, comment, flexible records and
+/// whitespace trimming, on top of the caller-supplied header flag.
+fn build_reader_builder(
+    input: &Value,
+    has_headers: bool,
+) -> Result<csv::ReaderBuilder, PluginError> {
+    let mut builder = csv::ReaderBuilder::new();
+    builder.has_headers(has_headers);
+
+    let dialect = match input.get("dialect") {
+        Some(d) => d,
+        None => return Ok(builder),
+    };
+
+    if let Some(delimiter) = char_field(dialect, "delimiter")? {
+        builder.delimiter(delimiter);
+    }
+    if let Some(quote) = char_field(dialect, "quote")? {
+        builder.quote(quote);
+    }
+    if let Some(escape) = char_field(dialect, "escape")? {
+        builder.escape(Some(escape));
+    }
+    if let Some(comment) = char_field(dialect, "comment")? {
+        builder.comment(Some(comment));
+    }
+    if let Some(flexible) = dialect.get("flexible").and_then(|v| v.as_bool()) {
+        builder.flexible(flexible);
+    }
+    if let Some(trim) = dialect.get("trim").and_then(|v| v.as_str()) {
+        let trim = match trim {
+            "none" => csv::Trim::None,
+            "headers" => csv::Trim::Headers,
+            "fields" => csv::Trim::Fields,
+            "all" => csv::Trim::All,
+            other => {
+                return Err(PluginError::malformed_payload(
+                    "csv",
+                    format!("Unknown trim mode: {}", other),
+                ))
+            }
+        };
+        builder.trim(trim);
+    }
+
+    Ok(builder)
+}
+
 /// Parse CSV string into array of arrays
-fn parse_csv(input: &Value) -> Result<Value, String> {
+fn parse_csv(input: &Value) -> Result<Value, PluginError> {
     let csv_data = input
         .get("data")
         .and_then(|v| v.as_str())
-        .ok_or("Missing 'data' field")?;
+        .ok_or_else(|| PluginError::missing_field("csv", "data"))?;
 
     let has_headers = input
         .get("headers")
         .and_then(|v| v.as_bool())
         .unwrap_or(true);
 
-    let mut reader = csv::ReaderBuilder::new()
-        .has_headers(has_headers)
-        .from_reader(csv_data.as_bytes());
+    if input.get("mode").and_then(|v| v.as_str()) == Some("stream") {
+        return parse_csv_stream(input, csv_data, has_headers);
+    }
+
+    let mut reader = build_reader_builder(input, has_headers)?.from_reader(csv_data.as_bytes());
 
     let mut rows: Vec<Vec<String>> = Vec::new();
 
@@ -159,7 +347,7 @@ fn parse_csv(input: &Value) -> Result<Value, String> {
     if has_headers {
         let headers: Vec<String> = reader
             .headers()
-            .map_err(|e| format!("Failed to read headers: {}", e))?
+            .map_err(|e| PluginError::from_csv_error("csv", &e))?
             .iter()
             .map(|s| s.to_string())
             .collect();
@@ -168,7 +356,7 @@ fn parse_csv(input: &Value) -> Result<Value, String> {
 
     // Read data rows
     for result in reader.records() {
-        let record = result.map_err(|e| format!("Failed to read record: {}", e))?;
+        let record = result.map_err(|e| PluginError::from_csv_error("csv", &e))?;
         let row: Vec<String> = record.iter().map(|s| s.to_string()).collect();
         rows.push(row);
     }
@@ -179,12 +367,131 @@ fn parse_csv(input: &Value) -> Result<Value, String> {
     }))
 }
 
+/// Extract the optional `max_rows` paging cap from the input.
+fn max_rows_field(input: &Value) -> Option<usize> {
+    input.get("max_rows").and_then(|v| v.as_u64()).map(|n| n as usize)
+}
+
+/// Parse an optional `resume` position — as previously returned in a
+/// `truncated` response — back into a `csv::Position` to seek the reader to,
+/// so a follow-up call can pick up where the last one left off instead of
+/// always restarting from byte 0.
+fn parse_resume_position(input: &Value) -> Result<Option<csv::Position>, PluginError> {
+    let resume = match input.get("resume") {
+        Some(v) => v,
+        None => return Ok(None),
+    };
+
+    let byte = resume
+        .get("byte")
+        .and_then(|v| v.as_u64())
+        .ok_or_else(|| PluginError::missing_field("csv", "resume.byte"))?;
+    let line = resume.get("line").and_then(|v| v.as_u64()).unwrap_or(1);
+    let record = resume.get("record").and_then(|v| v.as_u64()).unwrap_or(0);
+
+    let mut pos = csv::Position::new();
+    pos.set_byte(byte);
+    pos.set_line(line);
+    pos.set_record(record);
+    Ok(Some(pos))
+}
+
+/// Serialize a `csv::Position` into the `resume` marker returned alongside a
+/// `truncated` response.
+fn position_to_json(pos: &csv::Position) -> Value {
+    json!({
+        "byte": pos.byte(),
+        "line": pos.line(),
+        "record": pos.record()
+    })
+}
+
+/// Convert a single CSV field's bytes to UTF-8, amortizing allocation by
+/// reusing the caller's `csv::ByteRecord` across the read loop and only
+/// converting each field to `str` once.
+fn field_to_str(field: &[u8]) -> Result<&str, PluginError> {
+    std::str::from_utf8(field)
+        .map_err(|e| PluginError::invalid_utf8(format!("Invalid UTF-8 in field: {}", e)))
+}
+
+/// Streaming variant of `parse_csv` for large inputs: reuses a single
+/// `csv::ByteRecord` across the read loop instead of allocating a fresh
+/// record per row (the rows read are still collected into the returned
+/// `rows` array), and honors an optional `max_rows` cap. When
+/// the cap is hit, the response carries a `truncated` marker and a `resume`
+/// position; passing that position back in as the `resume` input seeks
+/// straight to the next unread record (skipping the already-emitted rows
+/// without re-reading them) while the header row, read and cached up front,
+/// is preserved across pages and not re-emitted.
+fn parse_csv_stream(input: &Value, csv_data: &str, has_headers: bool) -> Result<Value, PluginError> {
+    let max_rows = max_rows_field(input);
+    let resume = parse_resume_position(input)?;
+    let mut reader =
+        build_reader_builder(input, has_headers)?.from_reader(std::io::Cursor::new(csv_data.as_bytes()));
+
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    if has_headers {
+        let headers: Vec<String> = reader
+            .headers()
+            .map_err(|e| PluginError::from_csv_error("csv", &e))?
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        if resume.is_none() {
+            rows.push(headers);
+        }
+    }
+
+    if let Some(pos) = resume {
+        reader
+            .seek(pos)
+            .map_err(|e| PluginError::from_csv_error("csv", &e))?;
+    }
+
+    let mut record = csv::ByteRecord::new();
+    let mut data_rows = 0usize;
+    let mut truncated = false;
+
+    loop {
+        if let Some(max) = max_rows {
+            if data_rows >= max {
+                truncated = true;
+                break;
+            }
+        }
+
+        if !reader
+            .read_byte_record(&mut record)
+            .map_err(|e| PluginError::from_csv_error("csv", &e))?
+        {
+            break;
+        }
+
+        let mut row = Vec::with_capacity(record.len());
+        for field in record.iter() {
+            row.push(field_to_str(field)?.to_string());
+        }
+        rows.push(row);
+        data_rows += 1;
+    }
+
+    let mut result = json!({
+        "rows": rows,
+        "row_count": rows.len()
+    });
+    if truncated {
+        result["truncated"] = json!(true);
+        result["resume"] = position_to_json(reader.position());
+    }
+    Ok(result)
+}
+
 /// Format array of arrays as CSV string
-fn format_csv(input: &Value) -> Result<Value, String> {
+fn format_csv(input: &Value) -> Result<Value, PluginError> {
     let rows = input
         .get("rows")
         .and_then(|v| v.as_array())
-        .ok_or("Missing 'rows' field")?;
+        .ok_or_else(|| PluginError::missing_field("csv", "rows"))?;
 
     let delimiter = input
         .get("delimiter")
@@ -201,7 +508,7 @@ fn format_csv(input: &Value) -> Result<Value, String> {
     for row in rows {
         let fields: Vec<String> = row
             .as_array()
-            .ok_or("Row must be an array")?
+            .ok_or_else(|| PluginError::malformed_payload("csv", "Row must be an array"))?
             .iter()
             .map(|v| match v {
                 Value::String(s) => s.clone(),
@@ -211,48 +518,131 @@ fn format_csv(input: &Value) -> Result<Value, String> {
 
         writer
             .write_record(&fields)
-            .map_err(|e| format!("Failed to write record: {}", e))?;
+            .map_err(|e| PluginError::from_csv_error("csv", &e))?;
     }
 
     let data = writer
         .into_inner()
-        .map_err(|e| format!("Failed to finalize CSV: {}", e))?;
+        .map_err(|e| PluginError::malformed_payload("csv", format!("Failed to finalize CSV: {}", e)))?;
 
-    let csv_string =
-        String::from_utf8(data).map_err(|e| format!("Invalid UTF-8 in output: {}", e))?;
+    let csv_string = String::from_utf8(data)
+        .map_err(|e| PluginError::invalid_utf8(format!("Invalid UTF-8 in output: {}", e)))?;
 
     Ok(json!({
         "csv": csv_string
     }))
 }
 
+/// A column header, optionally annotated with a `name:type` coercion suffix.
+struct TypedColumn {
+    name: String,
+    kind: ColumnType,
+}
+
+/// The set of coercions a typed header can select.
+#[derive(Clone, Copy, PartialEq)]
+enum ColumnType {
+    String,
+    Number,
+    Boolean,
+}
+
+impl ColumnType {
+    fn from_suffix(suffix: &str) -> Option<Self> {
+        match suffix {
+            "string" => Some(ColumnType::String),
+            "number" => Some(ColumnType::Number),
+            "boolean" => Some(ColumnType::Boolean),
+            _ => None,
+        }
+    }
+}
+
+/// Split a header into its column name and coercion type.
+///
+/// The suffix after the *last* colon selects the type (`string`, `number`,
+/// `boolean`); an unrecognized or absent suffix leaves the header untouched
+/// and defaults to `string`.
+fn parse_typed_header(header: &str) -> TypedColumn {
+    match header.rsplit_once(':') {
+        Some((name, suffix)) => match ColumnType::from_suffix(suffix) {
+            Some(kind) => TypedColumn {
+                name: name.to_string(),
+                kind,
+            },
+            None => TypedColumn {
+                name: header.to_string(),
+                kind: ColumnType::String,
+            },
+        },
+        None => TypedColumn {
+            name: header.to_string(),
+            kind: ColumnType::String,
+        },
+    }
+}
+
+/// Coerce a raw CSV field into a JSON value according to its column type.
+///
+/// Empty cells become `null` for typed columns. A `number` field that fails
+/// to parse, or a `boolean` field that isn't `true`/`false` (case-insensitive),
+/// falls back to a plain JSON string.
+fn coerce_field(field: &str, kind: ColumnType) -> Value {
+    if field.is_empty() && kind != ColumnType::String {
+        return Value::Null;
+    }
+
+    match kind {
+        ColumnType::String => Value::String(field.to_string()),
+        ColumnType::Number => {
+            if let Ok(i) = field.parse::<i64>() {
+                json!(i)
+            } else if let Ok(f) = field.parse::<f64>() {
+                if f.is_finite() {
+                    json!(f)
+                } else {
+                    Value::String(field.to_string())
+                }
+            } else {
+                Value::String(field.to_string())
+            }
+        }
+        ColumnType::Boolean => match field.to_ascii_lowercase().as_str() {
+            "true" => Value::Bool(true),
+            "false" => Value::Bool(false),
+            _ => Value::String(field.to_string()),
+        },
+    }
+}
+
 /// Convert CSV to JSON array of objects
-fn csv_to_json(input: &Value) -> Result<Value, String> {
+fn csv_to_json(input: &Value) -> Result<Value, PluginError> {
     let csv_data = input
         .get("data")
         .and_then(|v| v.as_str())
-        .ok_or("Missing 'data' field")?;
+        .ok_or_else(|| PluginError::missing_field("csv", "data"))?;
 
-    let mut reader = csv::ReaderBuilder::new()
-        .has_headers(true)
-        .from_reader(csv_data.as_bytes());
+    let typed_headers = input
+        .get("typed_headers")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(true);
 
-    let headers: Vec<String> = reader
-        .headers()
-        .map_err(|e| format!("Failed to read headers: {}", e))?
-        .iter()
-        .map(|s| s.to_string())
-        .collect();
+    if input.get("mode").and_then(|v| v.as_str()) == Some("stream") {
+        return csv_to_json_stream(input, csv_data, typed_headers);
+    }
+
+    let mut reader = build_reader_builder(input, true)?.from_reader(csv_data.as_bytes());
+    let columns = resolve_typed_columns(&mut reader, typed_headers)?;
 
     let mut objects: Vec<Value> = Vec::new();
 
     for result in reader.records() {
-        let record = result.map_err(|e| format!("Failed to read record: {}", e))?;
+        let record = result.map_err(|e| PluginError::from_csv_error("csv", &e))?;
         let mut obj = serde_json::Map::new();
 
         for (i, field) in record.iter().enumerate() {
-            if let Some(header) = headers.get(i) {
-                obj.insert(header.clone(), Value::String(field.to_string()));
+            if let Some(column) = columns.get(i) {
+                obj.insert(column.name.clone(), coerce_field(field, column.kind));
             }
         }
 
@@ -265,6 +655,399 @@ fn csv_to_json(input: &Value) -> Result<Value, String> {
     }))
 }
 
+/// Read and classify the header row shared by `csv_to_json`'s streaming and
+/// non-streaming paths.
+fn resolve_typed_columns<R: std::io::Read>(
+    reader: &mut csv::Reader<R>,
+    typed_headers: bool,
+) -> Result<Vec<TypedColumn>, PluginError> {
+    Ok(reader
+        .headers()
+        .map_err(|e| PluginError::from_csv_error("csv", &e))?
+        .iter()
+        .map(|header| {
+            if typed_headers {
+                parse_typed_header(header)
+            } else {
+                TypedColumn {
+                    name: header.to_string(),
+                    kind: ColumnType::String,
+                }
+            }
+        })
+        .collect())
+}
+
+/// Streaming variant of `csv_to_json` for large inputs: reuses a single
+/// `csv::ByteRecord` across the read loop and converts each field to `str`
+/// only once, honoring an optional `max_rows` cap. When the cap is hit, the
+/// response carries a `truncated` marker and a `resume` position; passing
+/// that position back in as the `resume` input seeks straight to the next
+/// unread record, while the column set — resolved from the header row up
+/// front on every call — stays consistent across pages.
+fn csv_to_json_stream(
+    input: &Value,
+    csv_data: &str,
+    typed_headers: bool,
+) -> Result<Value, PluginError> {
+    let max_rows = max_rows_field(input);
+    let resume = parse_resume_position(input)?;
+    let mut reader = build_reader_builder(input, true)?.from_reader(std::io::Cursor::new(csv_data.as_bytes()));
+    let columns = resolve_typed_columns(&mut reader, typed_headers)?;
+
+    if let Some(pos) = resume {
+        reader
+            .seek(pos)
+            .map_err(|e| PluginError::from_csv_error("csv", &e))?;
+    }
+
+    let mut objects: Vec<Value> = Vec::new();
+    let mut record = csv::ByteRecord::new();
+    let mut truncated = false;
+
+    loop {
+        if let Some(max) = max_rows {
+            if objects.len() >= max {
+                truncated = true;
+                break;
+            }
+        }
+
+        if !reader
+            .read_byte_record(&mut record)
+            .map_err(|e| PluginError::from_csv_error("csv", &e))?
+        {
+            break;
+        }
+
+        let mut obj = serde_json::Map::new();
+        for (i, field) in record.iter().enumerate() {
+            if let Some(column) = columns.get(i) {
+                obj.insert(column.name.clone(), coerce_field(field_to_str(field)?, column.kind));
+            }
+        }
+        objects.push(Value::Object(obj));
+    }
+
+    let mut result = json!({
+        "objects": objects,
+        "count": objects.len()
+    });
+    if truncated {
+        result["truncated"] = json!(true);
+        result["resume"] = position_to_json(reader.position());
+    }
+    Ok(result)
+}
+
+/// Render a JSON value as a CSV field, matching `format_csv`'s convention of
+/// emitting strings verbatim and serializing everything else.
+fn value_to_field(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(v) => v.to_string(),
+    }
+}
+
+/// Determine the column order for a set of objects: honor an explicit
+/// `columns` list if given, otherwise the sorted union of all object keys.
+fn resolve_columns(
+    objects: &[Value],
+    input: &Value,
+    format: &'static str,
+) -> Result<Vec<String>, PluginError> {
+    if let Some(columns) = input.get("columns") {
+        let columns = columns
+            .as_array()
+            .ok_or_else(|| PluginError::malformed_payload(format, "'columns' must be an array"))?;
+        return columns
+            .iter()
+            .map(|c| {
+                c.as_str().map(|s| s.to_string()).ok_or_else(|| {
+                    PluginError::malformed_payload(format, "'columns' entries must be strings")
+                })
+            })
+            .collect();
+    }
+
+    let mut keys = std::collections::BTreeSet::new();
+    for object in objects {
+        let map = object
+            .as_object()
+            .ok_or_else(|| PluginError::malformed_payload(format, "Each record must be an object"))?;
+        keys.extend(map.keys().cloned());
+    }
+    Ok(keys.into_iter().collect())
+}
+
+/// Write a slice of JSON objects out as CSV using the given column order.
+fn write_objects_as_csv(
+    objects: &[Value],
+    columns: &[String],
+    format: &'static str,
+) -> Result<String, PluginError> {
+    let mut writer = csv::WriterBuilder::new().from_writer(vec![]);
+
+    writer
+        .write_record(columns)
+        .map_err(|e| PluginError::from_csv_error("csv", &e))?;
+
+    for object in objects {
+        let map = object
+            .as_object()
+            .ok_or_else(|| PluginError::malformed_payload(format, "Each record must be an object"))?;
+        let fields: Vec<String> = columns
+            .iter()
+            .map(|column| value_to_field(map.get(column)))
+            .collect();
+        writer
+            .write_record(&fields)
+            .map_err(|e| PluginError::from_csv_error("csv", &e))?;
+    }
+
+    let data = writer
+        .into_inner()
+        .map_err(|e| PluginError::malformed_payload("csv", format!("Failed to finalize CSV: {}", e)))?;
+
+    String::from_utf8(data).map_err(|e| PluginError::invalid_utf8(format!("Invalid UTF-8 in output: {}", e)))
+}
+
+/// Convert an array of JSON objects into a CSV string
+fn json_to_csv(input: &Value) -> Result<Value, PluginError> {
+    let objects = input
+        .get("objects")
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| PluginError::missing_field("json", "objects"))?;
+
+    let columns = resolve_columns(objects, input, "json")?;
+    let csv_string = write_objects_as_csv(objects, &columns, "json")?;
+
+    Ok(json!({ "csv": csv_string }))
+}
+
+/// Convert newline-delimited JSON (one object per line) into a CSV string
+fn ndjson_to_csv(input: &Value) -> Result<Value, PluginError> {
+    let ndjson_data = input
+        .get("data")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| PluginError::missing_field("ndjson", "data"))?;
+
+    let objects: Vec<Value> = serde_json::Deserializer::from_str(ndjson_data)
+        .into_iter::<Value>()
+        .collect::<Result<_, _>>()
+        .map_err(|e| PluginError::malformed_payload("ndjson", format!("Failed to parse NDJSON: {}", e)))?;
+
+    let columns = resolve_columns(&objects, input, "ndjson")?;
+    let csv_string = write_objects_as_csv(&objects, &columns, "ndjson")?;
+
+    Ok(json!({ "csv": csv_string }))
+}
+
+/// A small, dependency-free splitmix64 PRNG, used so `GenerateCSV` can
+/// produce reproducible fixtures from a `seed` without pulling in a full
+/// RNG crate.
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// A uniform `f64` in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// A uniform `i64` in `[lo, hi]` (inclusive).
+    fn next_range(&mut self, lo: i64, hi: i64) -> i64 {
+        let span = (hi - lo + 1) as u64;
+        lo + (self.next_u64() % span) as i64
+    }
+
+    fn next_bool(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+}
+
+/// The fixed set of column types `GenerateCSV` knows how to fabricate.
+enum GeneratorType {
+    Name,
+    Email,
+    Int,
+    Float,
+    Bool,
+    Uuid,
+    Date,
+}
+
+impl GeneratorType {
+    fn from_str(kind: &str) -> Option<Self> {
+        match kind {
+            "name" => Some(GeneratorType::Name),
+            "email" => Some(GeneratorType::Email),
+            "int" => Some(GeneratorType::Int),
+            "float" => Some(GeneratorType::Float),
+            "bool" => Some(GeneratorType::Bool),
+            "uuid" => Some(GeneratorType::Uuid),
+            "date" => Some(GeneratorType::Date),
+            _ => None,
+        }
+    }
+}
+
+struct GeneratorColumn {
+    name: String,
+    kind: GeneratorType,
+}
+
+const FIRST_NAMES: &[&str] = &[
+    "Alice", "Bob", "Carol", "David", "Emma", "Frank", "Grace", "Henry", "Iris", "Jack",
+];
+const LAST_NAMES: &[&str] = &[
+    "Smith", "Johnson", "Williams", "Brown", "Jones", "Garcia", "Miller", "Davis", "Lopez", "Lee",
+];
+
+/// Days-since-epoch to civil (year, month, day), via Howard Hinnant's
+/// public-domain `civil_from_days` algorithm.
+fn civil_from_days(z: i64) -> (i64, u32, u32) {
+    let z = z + 719468;
+    let era = if z >= 0 { z } else { z - 146096 } / 146097;
+    let doe = (z - era * 146097) as u64;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let y = yoe as i64 + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let y = if m <= 2 { y + 1 } else { y };
+    (y, m, d)
+}
+
+/// Civil (year, month, day) to days-since-epoch, the inverse of
+/// `civil_from_days`.
+fn days_from_civil(y: i64, m: u32, d: u32) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = (y - era * 400) as u64;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) as u64 + 2) / 5 + d as u64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe as i64 - 719468
+}
+
+fn parse_schema_entry(entry: &Value) -> Result<GeneratorColumn, PluginError> {
+    let name = entry
+        .get("name")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| PluginError::missing_field("csv", "schema[].name"))?
+        .to_string();
+
+    let type_name = entry
+        .get("type")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| PluginError::missing_field("csv", "schema[].type"))?;
+
+    let kind = GeneratorType::from_str(type_name).ok_or_else(|| {
+        PluginError::malformed_payload("csv", format!("Unknown schema type: {}", type_name))
+    })?;
+
+    Ok(GeneratorColumn { name, kind })
+}
+
+/// Generate one field's synthetic value for the given column type.
+fn generate_value(kind: &GeneratorType, rng: &mut SplitMix64) -> String {
+    match kind {
+        GeneratorType::Name => format!(
+            "{} {}",
+            FIRST_NAMES[rng.next_range(0, FIRST_NAMES.len() as i64 - 1) as usize],
+            LAST_NAMES[rng.next_range(0, LAST_NAMES.len() as i64 - 1) as usize]
+        ),
+        GeneratorType::Email => {
+            let first = FIRST_NAMES[rng.next_range(0, FIRST_NAMES.len() as i64 - 1) as usize];
+            let last = LAST_NAMES[rng.next_range(0, LAST_NAMES.len() as i64 - 1) as usize];
+            format!(
+                "{}.{}@example.com",
+                first.to_lowercase(),
+                last.to_lowercase()
+            )
+        }
+        GeneratorType::Int => rng.next_range(0, 1000).to_string(),
+        GeneratorType::Float => format!("{:.2}", rng.next_range(0, 100_000) as f64 / 100.0),
+        GeneratorType::Bool => rng.next_bool().to_string(),
+        GeneratorType::Uuid => format!(
+            "{:08x}-{:04x}-4{:03x}-{:04x}-{:012x}",
+            rng.next_u64() as u32,
+            rng.next_u64() as u16,
+            rng.next_u64() & 0xFFF,
+            (rng.next_u64() & 0x3FFF) | 0x8000,
+            rng.next_u64() & 0xFFFFFFFFFFFF
+        ),
+        GeneratorType::Date => {
+            let base_days = days_from_civil(2020, 1, 1);
+            let offset = (rng.next_f64() * 3650.0) as i64;
+            let (y, m, d) = civil_from_days(base_days + offset);
+            format!("{:04}-{:02}-{:02}", y, m, d)
+        }
+    }
+}
+
+/// Generate synthetic CSV data from a column `schema`, useful for seeding
+/// tests and demos without external tooling.
+fn generate_csv(input: &Value) -> Result<Value, PluginError> {
+    let schema = input
+        .get("schema")
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| PluginError::missing_field("csv", "schema"))?;
+
+    let rows = input
+        .get("rows")
+        .and_then(|v| v.as_u64())
+        .ok_or_else(|| PluginError::missing_field("csv", "rows"))?;
+
+    let seed = input.get("seed").and_then(|v| v.as_u64()).unwrap_or(0);
+
+    let columns: Vec<GeneratorColumn> = schema
+        .iter()
+        .map(parse_schema_entry)
+        .collect::<Result<_, _>>()?;
+
+    let mut rng = SplitMix64::new(seed);
+    let mut writer = csv::WriterBuilder::new().from_writer(vec![]);
+
+    let headers: Vec<&str> = columns.iter().map(|c| c.name.as_str()).collect();
+    writer
+        .write_record(&headers)
+        .map_err(|e| PluginError::from_csv_error("csv", &e))?;
+
+    for _ in 0..rows {
+        let fields: Vec<String> = columns
+            .iter()
+            .map(|c| generate_value(&c.kind, &mut rng))
+            .collect();
+        writer
+            .write_record(&fields)
+            .map_err(|e| PluginError::from_csv_error("csv", &e))?;
+    }
+
+    let data = writer
+        .into_inner()
+        .map_err(|e| PluginError::malformed_payload("csv", format!("Failed to finalize CSV: {}", e)))?;
+    let csv_string = String::from_utf8(data)
+        .map_err(|e| PluginError::invalid_utf8(format!("Invalid UTF-8 in output: {}", e)))?;
+
+    Ok(json!({ "csv": csv_string }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -289,4 +1072,273 @@ mod tests {
         let result = csv_to_json(&input).unwrap();
         assert_eq!(result["count"], 2);
     }
+
+    #[test]
+    fn test_csv_to_json_typed_headers() {
+        let input = json!({
+            "data": "name:string,age:number,active:boolean\nAlice,30,true\nBob,,FALSE"
+        });
+
+        let result = csv_to_json(&input).unwrap();
+        let objects = result["objects"].as_array().unwrap();
+        assert_eq!(objects[0]["age"], json!(30));
+        assert_eq!(objects[0]["active"], json!(true));
+        assert_eq!(objects[1]["age"], Value::Null);
+        assert_eq!(objects[1]["active"], json!(false));
+    }
+
+    #[test]
+    fn test_csv_to_json_typed_headers_opt_out() {
+        let input = json!({
+            "data": "name:string,age:number\nAlice,30",
+            "typed_headers": false
+        });
+
+        let result = csv_to_json(&input).unwrap();
+        let objects = result["objects"].as_array().unwrap();
+        assert_eq!(objects[0]["age:number"], json!("30"));
+    }
+
+    #[test]
+    fn test_csv_to_json_number_coercion_rejects_non_finite() {
+        let input = json!({
+            "data": "x:number\ninf\nNaN\n-Infinity"
+        });
+
+        let result = csv_to_json(&input).unwrap();
+        let objects = result["objects"].as_array().unwrap();
+        assert_eq!(objects[0]["x"], json!("inf"));
+        assert_eq!(objects[1]["x"], json!("NaN"));
+        assert_eq!(objects[2]["x"], json!("-Infinity"));
+    }
+
+    #[test]
+    fn test_json_to_csv() {
+        let input = json!({
+            "objects": [
+                { "name": "Alice", "age": 30 },
+                { "name": "Bob", "age": 25 }
+            ]
+        });
+
+        let result = json_to_csv(&input).unwrap();
+        assert_eq!(result["csv"], "age,name\n30,Alice\n25,Bob\n");
+    }
+
+    #[test]
+    fn test_json_to_csv_with_columns() {
+        let input = json!({
+            "objects": [{ "name": "Alice", "age": 30 }],
+            "columns": ["name", "age", "city"]
+        });
+
+        let result = json_to_csv(&input).unwrap();
+        assert_eq!(result["csv"], "name,age,city\nAlice,30,\n");
+    }
+
+    #[test]
+    fn test_ndjson_to_csv() {
+        let input = json!({
+            "data": "{\"name\":\"Alice\",\"age\":30}\n{\"name\":\"Bob\",\"age\":25}\n"
+        });
+
+        let result = ndjson_to_csv(&input).unwrap();
+        assert_eq!(result["csv"], "age,name\n30,Alice\n25,Bob\n");
+    }
+
+    #[test]
+    fn test_parse_csv_tab_delimited() {
+        let input = json!({
+            "data": "name\tage\nAlice\t30",
+            "dialect": { "delimiter": "\t" }
+        });
+
+        let result = parse_csv(&input).unwrap();
+        assert_eq!(result["rows"][1][0], "Alice");
+        assert_eq!(result["rows"][1][1], "30");
+    }
+
+    #[test]
+    fn test_parse_csv_rejects_non_ascii_delimiter() {
+        let input = json!({
+            "data": "name;age\nAlice;30",
+            "dialect": { "delimiter": "；" }
+        });
+
+        let err = parse_csv(&input).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::MalformedPayload);
+    }
+
+    #[test]
+    fn test_csv_to_json_with_comments_and_trim() {
+        let input = json!({
+            "data": "# this is a comment\nname, age:number\n Alice , 30 ",
+            "dialect": { "comment": "#", "trim": "all" }
+        });
+
+        let result = csv_to_json(&input).unwrap();
+        let objects = result["objects"].as_array().unwrap();
+        assert_eq!(objects[0]["name"], "Alice");
+        assert_eq!(objects[0]["age"], json!(30));
+    }
+
+    #[test]
+    fn test_csv_to_json_missing_field_error() {
+        let input = json!({});
+
+        let err = csv_to_json(&input).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::MissingField);
+        assert_eq!(err.format, Some("csv"));
+    }
+
+    #[test]
+    fn test_csv_to_json_malformed_payload_error_has_position() {
+        let input = json!({
+            "data": "a,b\n1,2,3"
+        });
+
+        let err = csv_to_json(&input).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::MalformedPayload);
+        assert_eq!(err.format, Some("csv"));
+        assert_eq!(err.line, Some(2));
+    }
+
+    #[test]
+    fn test_unknown_action_error() {
+        let err = PluginError::unknown_action("frobnicate");
+        assert_eq!(err.kind, ErrorKind::UnknownAction);
+        assert!(err.format.is_none());
+    }
+
+    #[test]
+    fn test_parse_csv_stream_mode() {
+        let input = json!({
+            "data": "name,age\nAlice,30\nBob,25",
+            "mode": "stream"
+        });
+
+        let result = parse_csv(&input).unwrap();
+        assert_eq!(result["row_count"], 3);
+        assert_eq!(result["rows"][1][0], "Alice");
+        assert!(result.get("truncated").is_none());
+    }
+
+    #[test]
+    fn test_parse_csv_stream_mode_max_rows() {
+        let input = json!({
+            "data": "name,age\nAlice,30\nBob,25\nCarol,40",
+            "mode": "stream",
+            "max_rows": 1
+        });
+
+        let result = parse_csv(&input).unwrap();
+        assert_eq!(result["rows"].as_array().unwrap().len(), 2);
+        assert_eq!(result["truncated"], true);
+        assert!(result["resume"]["byte"].as_u64().unwrap() > 0);
+    }
+
+    #[test]
+    fn test_parse_csv_stream_mode_resume_continues_past_first_page() {
+        let data = "name,age\nAlice,30\nBob,25\nCarol,40";
+
+        let first = parse_csv(&json!({
+            "data": data,
+            "mode": "stream",
+            "max_rows": 1
+        }))
+        .unwrap();
+        assert_eq!(first["truncated"], true);
+        let resume = first["resume"].clone();
+
+        let second = parse_csv(&json!({
+            "data": data,
+            "mode": "stream",
+            "max_rows": 1,
+            "resume": resume
+        }))
+        .unwrap();
+
+        // The resumed page picks up at Bob, not at the header or at Alice again.
+        assert_eq!(second["rows"].as_array().unwrap().len(), 1);
+        assert_eq!(second["rows"][0][0], "Bob");
+        assert_eq!(second["truncated"], true);
+
+        let third = parse_csv(&json!({
+            "data": data,
+            "mode": "stream",
+            "max_rows": 1,
+            "resume": second["resume"].clone()
+        }))
+        .unwrap();
+        assert_eq!(third["rows"].as_array().unwrap().len(), 1);
+        assert_eq!(third["rows"][0][0], "Carol");
+
+        let fourth = parse_csv(&json!({
+            "data": data,
+            "mode": "stream",
+            "max_rows": 1,
+            "resume": third["resume"].clone()
+        }))
+        .unwrap();
+        assert_eq!(fourth["rows"].as_array().unwrap().len(), 0);
+        assert!(fourth.get("truncated").is_none());
+    }
+
+    #[test]
+    fn test_csv_to_json_stream_mode() {
+        let input = json!({
+            "data": "name,age:number\nAlice,30\nBob,25",
+            "mode": "stream"
+        });
+
+        let result = csv_to_json(&input).unwrap();
+        let objects = result["objects"].as_array().unwrap();
+        assert_eq!(objects.len(), 2);
+        assert_eq!(objects[0]["age"], json!(30));
+    }
+
+    #[test]
+    fn test_generate_csv() {
+        let input = json!({
+            "schema": [
+                { "name": "id", "type": "uuid" },
+                { "name": "name", "type": "name" },
+                { "name": "age", "type": "int" },
+                { "name": "active", "type": "bool" },
+                { "name": "signup_date", "type": "date" }
+            ],
+            "rows": 5,
+            "seed": 42
+        });
+
+        let result = generate_csv(&input).unwrap();
+        let csv = result["csv"].as_str().unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next().unwrap(), "id,name,age,active,signup_date");
+        assert_eq!(lines.count(), 5);
+    }
+
+    #[test]
+    fn test_generate_csv_is_deterministic_for_seed() {
+        let input = json!({
+            "schema": [{ "name": "id", "type": "uuid" }],
+            "rows": 3,
+            "seed": 7
+        });
+
+        let first = generate_csv(&input).unwrap();
+        let second = generate_csv(&input).unwrap();
+        assert_eq!(first["csv"], second["csv"]);
+    }
+
+    #[test]
+    fn test_generate_csv_unknown_type_error() {
+        let input = json!({
+            "schema": [{ "name": "id", "type": "not-a-type" }],
+            "rows": 1
+        });
+
+        let err = generate_csv(&input).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::MalformedPayload);
+    }
 }